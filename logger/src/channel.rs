@@ -0,0 +1,179 @@
+// ============================================================
+// ОБМЕЖЕНИЙ ЗА РОЗМІРОМ КАНАЛ МІЖ МАКРОСАМИ ТА ПОТОКОМ ЛОГЕРА
+// ============================================================
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::Duration;
+
+use crate::LogRecord;
+
+// ============================================================
+// ПОЛІТИКА ПОВЕДІНКИ ПРИ ПЕРЕПОВНЕННІ
+// ============================================================
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OverflowPolicy {
+    /// Чекати, поки в черзі з'явиться місце
+    Block,
+    /// Відкинути запис, що не влізає
+    DropNewest,
+    /// Звільнити місце, викинувши найстаріший запис у черзі
+    DropOldest,
+}
+
+// ============================================================
+// ЛІЧИЛЬНИК ВІДКИНУТИХ ПОВІДОМЛЕНЬ (глобальний, атомарний)
+// ============================================================
+static DROPPED_COUNT: AtomicU64 = AtomicU64::new(0);
+
+/// Скільки повідомлень було відкинуто через `OverflowPolicy::DropNewest`/`DropOldest`.
+pub fn dropped_count() -> u64 {
+    DROPPED_COUNT.load(Ordering::Relaxed)
+}
+
+struct Shared {
+    queue: Mutex<VecDeque<LogRecord>>,
+    not_empty: Condvar,
+    not_full: Condvar,
+    capacity: usize,
+    // Встановлюється, коли LogReceiver знищується (наприклад, потік-хендлер запанікував),
+    // щоб заблоковані на Block-політиці відправники не чекали вічно.
+    disconnected: AtomicBool,
+}
+
+pub struct LogSender {
+    shared: Arc<Shared>,
+    policy: OverflowPolicy,
+}
+
+pub struct LogReceiver {
+    shared: Arc<Shared>,
+}
+
+/// Створює пару sender/receiver з обмеженою ємністю черги.
+pub fn bounded(capacity: usize, policy: OverflowPolicy) -> (LogSender, LogReceiver) {
+    let shared = Arc::new(Shared {
+        queue: Mutex::new(VecDeque::with_capacity(capacity)),
+        not_empty: Condvar::new(),
+        not_full: Condvar::new(),
+        capacity,
+        disconnected: AtomicBool::new(false),
+    });
+
+    (
+        LogSender { shared: shared.clone(), policy },
+        LogReceiver { shared },
+    )
+}
+
+impl LogSender {
+    /// Повертає `Err` із записом назад, якщо на іншому кінці вже немає `LogReceiver`
+    /// (потік-хендлер завершився або запанікував) - замість вічного очікування.
+    pub fn send(&self, record: LogRecord) -> Result<(), LogRecord> {
+        let mut queue = self.shared.queue.lock().unwrap();
+
+        if self.shared.disconnected.load(Ordering::SeqCst) {
+            return Err(record);
+        }
+
+        if queue.len() >= self.shared.capacity {
+            match self.policy {
+                OverflowPolicy::Block => {
+                    while queue.len() >= self.shared.capacity {
+                        if self.shared.disconnected.load(Ordering::SeqCst) {
+                            return Err(record);
+                        }
+                        queue = self.shared.not_full.wait(queue).unwrap();
+                    }
+                }
+                OverflowPolicy::DropNewest => {
+                    DROPPED_COUNT.fetch_add(1, Ordering::Relaxed);
+                    return Ok(());
+                }
+                OverflowPolicy::DropOldest => {
+                    queue.pop_front();
+                    DROPPED_COUNT.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+        }
+
+        queue.push_back(record);
+        self.shared.not_empty.notify_one();
+        Ok(())
+    }
+}
+
+impl LogReceiver {
+    /// Блокується до появи запису або до спливання таймауту.
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<LogRecord> {
+        let mut queue = self.shared.queue.lock().unwrap();
+
+        while queue.is_empty() {
+            let (guard, result) = self.shared.not_empty.wait_timeout(queue, timeout).unwrap();
+            queue = guard;
+            if result.timed_out() && queue.is_empty() {
+                return None;
+            }
+        }
+
+        let record = queue.pop_front();
+        self.shared.not_full.notify_one();
+        record
+    }
+}
+
+impl Drop for LogReceiver {
+    fn drop(&mut self) {
+        self.shared.disconnected.store(true, Ordering::SeqCst);
+
+        // Будимо всіх відправників, що чекають на Block-політиці, аби вони побачили розрив
+        let _queue = self.shared.queue.lock().unwrap();
+        self.shared.not_full.notify_all();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::sample_record as record;
+
+    #[test]
+    fn drop_oldest_evicts_front_and_counts() {
+        let (tx, rx) = bounded(2, OverflowPolicy::DropOldest);
+        let before = dropped_count();
+
+        tx.send(record("one", 0)).unwrap();
+        tx.send(record("two", 0)).unwrap();
+        tx.send(record("three", 0)).unwrap();
+
+        assert!(dropped_count() >= before + 1);
+        assert_eq!(rx.recv_timeout(Duration::from_secs(1)).unwrap().msg, "two");
+        assert_eq!(rx.recv_timeout(Duration::from_secs(1)).unwrap().msg, "three");
+    }
+
+    #[test]
+    fn drop_newest_keeps_queue_and_counts() {
+        let (tx, rx) = bounded(1, OverflowPolicy::DropNewest);
+        let before = dropped_count();
+
+        tx.send(record("kept", 0)).unwrap();
+        tx.send(record("dropped", 0)).unwrap();
+
+        assert!(dropped_count() >= before + 1);
+        assert_eq!(rx.recv_timeout(Duration::from_secs(1)).unwrap().msg, "kept");
+        assert!(rx.recv_timeout(Duration::from_millis(50)).is_none());
+    }
+
+    #[test]
+    fn send_on_block_policy_fails_instead_of_blocking_forever_once_receiver_drops() {
+        let (tx, rx) = bounded(1, OverflowPolicy::Block);
+
+        tx.send(record("fills the queue", 0)).unwrap();
+        drop(rx);
+
+        // Черга все ще повна, а отримувача більше немає - раніше send() чекав би вічно.
+        let result = tx.send(record("should not block", 0));
+        assert!(result.is_err());
+    }
+}
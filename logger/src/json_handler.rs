@@ -0,0 +1,99 @@
+// ============================================================
+// СТРУКТУРОВАНИЙ JSON-ХЕНДЛЕР
+// ============================================================
+
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::{LogHandler, LogRecord};
+
+#[derive(Serialize)]
+struct JsonRecord<'a> {
+    heading: &'a str,
+    target: &'a str,
+    lvl: i32,
+    timestamp: String,
+    msg: &'a str,
+}
+
+// ============================================================
+// ХЕНДЛЕР: пише кожен запис як один JSON-об'єкт на рядок
+// ============================================================
+pub struct JsonHandler {
+    writer: Box<dyn Write + Send>,
+}
+
+impl JsonHandler {
+    pub fn to_file(path: impl AsRef<Path>) -> Self {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .expect("Failed to open log file");
+
+        Self { writer: Box::new(file) }
+    }
+
+    pub fn to_writer(writer: impl Write + Send + 'static) -> Self {
+        Self { writer: Box::new(writer) }
+    }
+}
+
+impl LogHandler for JsonHandler {
+    fn handle(&mut self, record: &LogRecord) {
+        let json_record = JsonRecord {
+            heading: record.heading,
+            target: &record.target,
+            lvl: record.lvl,
+            timestamp: record.timestamp.to_rfc3339(),
+            msg: &record.msg,
+        };
+
+        if let Ok(line) = serde_json::to_string(&json_record) {
+            writeln!(self.writer, "{}", line).ok();
+        }
+    }
+
+    fn flush(&mut self) {
+        self.writer.flush().ok();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::sample_record as record;
+    use std::sync::{Arc, Mutex};
+
+    // Обгортка, що дозволяє прочитати вміст буфера вже після того, як він
+    // переданий у JsonHandler::to_writer (якому потрібен 'static Write).
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn writes_one_json_object_per_line() {
+        let shared = SharedBuffer::default();
+        let mut handler = JsonHandler::to_writer(shared.clone());
+
+        handler.handle(&record("hello", 2));
+
+        let output = String::from_utf8(shared.0.lock().unwrap().clone()).unwrap();
+        let line = output.lines().next().expect("expected one JSON line");
+
+        assert!(line.contains("\"msg\":\"hello\""));
+        assert!(line.contains("\"lvl\":2"));
+    }
+}
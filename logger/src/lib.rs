@@ -4,9 +4,28 @@
 // ============================================================
 
 mod sub_func;
+mod memory;
+mod channel;
+mod rotating_file;
+mod json_handler;
+#[cfg(feature = "log-facade")]
+mod log_facade;
+#[cfg(test)]
+mod test_support;
 
 // Ре-експортуємо все публічне з sub_func
 pub use sub_func::*;
+// Ре-експортуємо in-memory хендлер та query/filter API
+pub use memory::*;
+// Ре-експортуємо політику переповнення каналу та лічильник відкинутих повідомлень
+pub use channel::{OverflowPolicy, dropped_count};
+// Ре-експортуємо файловий хендлер з ротацією
+pub use rotating_file::*;
+// Ре-експортуємо JSON-хендлер
+pub use json_handler::*;
+// Ре-експортуємо міст до крейта `log`
+#[cfg(feature = "log-facade")]
+pub use log_facade::*;
 
 // Ре-експортуємо залежності для макросів
 pub use chrono;
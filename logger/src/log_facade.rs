@@ -0,0 +1,65 @@
+// ============================================================
+// МІСТ ДО КРЕЙТА `log` (увімкнено фічею "log-facade")
+// Дозволяє сторонім залежностям, що пишуть через log::info!/warn!/...,
+// писати в цей же логер без зміни їхнього коду.
+// ============================================================
+
+use log::{Level, Metadata, Record};
+
+use crate::{internal_send_log, LogRecord};
+
+struct LoggerBridge;
+
+// Відповідність рівнів `log::Level` внутрішнім (колір, заголовок, ціле значення)
+fn map_level(level: Level) -> (i32, &'static str, &'static str) {
+    match level {
+        Level::Error => (4, "\x1b[31m", "ERROR"),
+        Level::Warn => (3, "\x1b[33m", "WARN"),
+        Level::Info => (2, "\x1b[32m", "INFO"),
+        Level::Debug => (1, "\x1b[36m", "DEBUG"),
+        Level::Trace => (0, "\x1b[90m", "TRACE"),
+    }
+}
+
+impl log::Log for LoggerBridge {
+    fn enabled(&self, _metadata: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        let (lvl, color, heading) = map_level(record.level());
+
+        internal_send_log(LogRecord {
+            color,
+            heading,
+            target: record.target().to_string(),
+            msg: format!("{}", record.args()),
+            timestamp: chrono::Utc::now(),
+            lvl,
+        });
+    }
+
+    fn flush(&self) {}
+}
+
+// ============================================================
+// ПУБЛІЧНА ФУНКЦІЯ: Встановлення фасаду `log` як глобального логера
+// ============================================================
+pub fn init_log_facade(min_level: log::LevelFilter) -> Result<(), log::SetLoggerError> {
+    log::set_max_level(min_level);
+    log::set_boxed_logger(Box::new(LoggerBridge))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_log_levels_onto_internal_severity_in_ascending_order() {
+        assert_eq!(map_level(Level::Trace).0, 0);
+        assert_eq!(map_level(Level::Debug).0, 1);
+        assert_eq!(map_level(Level::Info).0, 2);
+        assert_eq!(map_level(Level::Warn).0, 3);
+        assert_eq!(map_level(Level::Error).0, 4);
+    }
+}
@@ -0,0 +1,202 @@
+// ============================================================
+// IN-MEMORY ХЕНДЛЕР (кільцевий буфер з query/filter API)
+// ============================================================
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::{LogHandler, LogRecord};
+
+// ============================================================
+// СНІМОК ЗАПИСУ ЛОГУ (те, що зберігається в буфері)
+// ============================================================
+pub struct StoredRecord {
+    pub color: &'static str,
+    pub heading: &'static str,
+    pub target: String,
+    pub msg: String,
+    pub timestamp: DateTime<Utc>,
+    pub lvl: i32,
+}
+
+impl From<&LogRecord> for StoredRecord {
+    fn from(record: &LogRecord) -> Self {
+        Self {
+            color: record.color,
+            heading: record.heading,
+            target: record.target.clone(),
+            msg: record.msg.clone(),
+            timestamp: record.timestamp,
+            lvl: record.lvl,
+        }
+    }
+}
+
+// ============================================================
+// ФІЛЬТР ДЛЯ query()
+// ============================================================
+#[derive(Default)]
+pub struct RecordFilter {
+    pub min_level: Option<i32>,
+    pub target: Option<String>,
+    pub regex: Option<regex::Regex>,
+    pub not_before: Option<DateTime<Utc>>,
+    pub limit: u32,
+}
+
+impl RecordFilter {
+    fn matches(&self, record: &StoredRecord) -> bool {
+        if let Some(min_level) = self.min_level {
+            if record.lvl < min_level {
+                return false;
+            }
+        }
+
+        if let Some(target) = &self.target {
+            if &record.target != target {
+                return false;
+            }
+        }
+
+        if let Some(regex) = &self.regex {
+            if !regex.is_match(&record.msg) {
+                return false;
+            }
+        }
+
+        if let Some(not_before) = self.not_before {
+            if record.timestamp < not_before {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+// ============================================================
+// ГЛОБАЛЬНИЙ БУФЕР (приватний)
+// ============================================================
+static BUFFER: OnceLock<Arc<Mutex<VecDeque<Arc<StoredRecord>>>>> = OnceLock::new();
+
+fn buffer() -> &'static Arc<Mutex<VecDeque<Arc<StoredRecord>>>> {
+    BUFFER.get_or_init(|| Arc::new(Mutex::new(VecDeque::new())))
+}
+
+// ============================================================
+// ХЕНДЛЕР: тримає останні N записів / записи не старіші за retention
+// ============================================================
+pub struct MemoryHandler {
+    buffer: Arc<Mutex<VecDeque<Arc<StoredRecord>>>>,
+    max_records: Option<usize>,
+}
+
+impl MemoryHandler {
+    /// `max_records` обмежує кількість записів, `retention` - максимальний вік запису.
+    /// Можна задати обидва, одне з них, або жодного (без обмежень).
+    pub fn new(max_records: Option<usize>, retention: Option<Duration>) -> Self {
+        let buffer = buffer().clone();
+
+        if let Some(retention) = retention {
+            let buffer = buffer.clone();
+            std::thread::spawn(move || loop {
+                std::thread::sleep(std::time::Duration::from_secs(1));
+                evict_expired(&buffer, retention);
+            });
+        }
+
+        Self { buffer, max_records }
+    }
+}
+
+impl LogHandler for MemoryHandler {
+    fn handle(&mut self, record: &LogRecord) {
+        let mut buffer = self.buffer.lock().unwrap();
+        buffer.push_back(Arc::new(StoredRecord::from(record)));
+
+        if let Some(max_records) = self.max_records {
+            while buffer.len() > max_records {
+                buffer.pop_front();
+            }
+        }
+    }
+}
+
+fn evict_expired(buffer: &Arc<Mutex<VecDeque<Arc<StoredRecord>>>>, retention: Duration) {
+    let cutoff = Utc::now() - retention;
+    let mut buffer = buffer.lock().unwrap();
+
+    while buffer.front().map_or(false, |record| record.timestamp < cutoff) {
+        buffer.pop_front();
+    }
+}
+
+// ============================================================
+// ПУБЛІЧНА ФУНКЦІЯ: Вибірка записів з буфера
+// ============================================================
+pub fn query(filter: RecordFilter) -> Vec<Arc<StoredRecord>> {
+    // `limit: 0` (в тому числі `RecordFilter::default()`) означає "без обмеження",
+    // а не "нічого не повертати"
+    let limit = if filter.limit == 0 { usize::MAX } else { filter.limit as usize };
+
+    let buffer = buffer().lock().unwrap();
+
+    buffer
+        .iter()
+        .rev()
+        .filter(|record| filter.matches(record))
+        .take(limit)
+        .cloned()
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::sample_record as record;
+
+    #[test]
+    fn retention_evicts_records_older_than_the_window() {
+        let buffer: Arc<Mutex<VecDeque<Arc<StoredRecord>>>> = Arc::new(Mutex::new(VecDeque::new()));
+
+        {
+            let mut guard = buffer.lock().unwrap();
+            guard.push_back(Arc::new(StoredRecord::from(&record("stale", 0))));
+        }
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        {
+            let mut guard = buffer.lock().unwrap();
+            guard.push_back(Arc::new(StoredRecord::from(&record("fresh", 0))));
+        }
+
+        evict_expired(&buffer, Duration::milliseconds(10));
+
+        let remaining = buffer.lock().unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].msg, "fresh");
+    }
+
+    #[test]
+    fn query_with_default_filter_limit_is_unbounded() {
+        let mut handler = MemoryHandler::new(None, None);
+
+        for i in 0..5 {
+            let mut rec = record(&format!("msg-{}", i), 0);
+            rec.target = "memory_query_test_target".to_string();
+            handler.handle(&rec);
+        }
+
+        // Навмисно НЕ задаємо limit - RecordFilter::default() не повинен повертати порожній Vec
+        let results = query(RecordFilter {
+            target: Some("memory_query_test_target".to_string()),
+            ..Default::default()
+        });
+
+        assert_eq!(results.len(), 5);
+    }
+}
@@ -0,0 +1,115 @@
+// ============================================================
+// ФАЙЛОВИЙ ХЕНДЛЕР З РОТАЦІЄЮ ЗА РОЗМІРОМ
+// ============================================================
+
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+use crate::{LogHandler, LogRecord};
+
+/// Типовий ліміт розміру файлу до ротації (як `DEFAULT_FILE_CAPACITY` у Fuchsia log_listener)
+pub const DEFAULT_FILE_CAPACITY: u64 = 64 * 1024;
+
+// ============================================================
+// ХЕНДЛЕР: пише у файл, ротуючи app.log -> app.log.1 -> ... при перевищенні capacity
+// ============================================================
+pub struct RotatingFileHandler {
+    path: PathBuf,
+    file: File,
+    capacity: u64,
+    max_generations: u32,
+    written: u64,
+}
+
+impl RotatingFileHandler {
+    pub fn new(path: impl Into<PathBuf>, capacity: u64, max_generations: u32) -> Self {
+        let path = path.into();
+        let file = Self::open(&path);
+        let written = file.metadata().map(|m| m.len()).unwrap_or(0);
+
+        Self { path, file, capacity, max_generations, written }
+    }
+
+    fn open(path: &PathBuf) -> File {
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .expect("Failed to open log file")
+    }
+
+    fn rotated_path(&self, generation: u32) -> PathBuf {
+        let mut name = self.path.clone().into_os_string();
+        name.push(format!(".{}", generation));
+        PathBuf::from(name)
+    }
+
+    fn rotate(&mut self) {
+        self.file.flush().ok();
+
+        // Зсуваємо старі покоління: app.log.N -> app.log.(N+1)
+        for generation in (1..self.max_generations).rev() {
+            let from = self.rotated_path(generation);
+            let to = self.rotated_path(generation + 1);
+            if from.exists() {
+                fs::rename(&from, &to).ok();
+            }
+        }
+
+        fs::rename(&self.path, self.rotated_path(1)).ok();
+
+        self.file = Self::open(&self.path);
+        self.written = 0;
+    }
+}
+
+impl LogHandler for RotatingFileHandler {
+    fn handle(&mut self, record: &LogRecord) {
+        let line = format!(
+            "[{}] {} - {}\n",
+            record.heading,
+            record.timestamp.format("%Y-%m-%d %H:%M:%S"),
+            record.msg
+        );
+
+        if self.written + line.len() as u64 > self.capacity {
+            self.rotate();
+        }
+
+        if self.file.write_all(line.as_bytes()).is_ok() {
+            self.written += line.len() as u64;
+        }
+    }
+
+    fn flush(&mut self) {
+        self.file.flush().ok();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::sample_record as record;
+
+    #[test]
+    fn rotates_once_capacity_is_exceeded() {
+        let path = std::env::temp_dir()
+            .join(format!("sample_logger_rotation_test_{}.log", std::process::id()));
+        let mut rotated_name = path.clone().into_os_string();
+        rotated_name.push(".1");
+        let rotated = PathBuf::from(rotated_name);
+
+        let _ = fs::remove_file(&path);
+        let _ = fs::remove_file(&rotated);
+
+        let mut handler = RotatingFileHandler::new(&path, 10, 3);
+        handler.handle(&record("first message is longer than ten bytes", 0));
+        handler.handle(&record("second", 0));
+
+        assert!(rotated.exists());
+
+        fs::remove_file(&path).ok();
+        fs::remove_file(&rotated).ok();
+    }
+}
@@ -2,12 +2,13 @@
 // ВНУТРІШНЯ РЕАЛІЗАЦІЯ ЛОГЕРА
 // ============================================================
 
-use std::sync::mpsc::{Sender, Receiver, channel};
 use std::sync::OnceLock;
+use std::time::Duration as StdDuration;
 use chrono::{DateTime, Datelike, Timelike, Utc};
 use colored::*;
 
 use crate::LogHandler;
+use crate::channel::{bounded, dropped_count, LogReceiver, LogSender, OverflowPolicy};
 
 // ============================================================
 // СТРУКТУРА ЗАПИСУ ЛОГУ (публічна для хендлерів)
@@ -15,6 +16,7 @@ use crate::LogHandler;
 pub struct LogRecord {
     pub color: &'static str,
     pub heading: &'static str,
+    pub target: String,
     pub msg: String,
     pub timestamp: DateTime<Utc>,
     pub lvl: i32,
@@ -23,16 +25,42 @@ pub struct LogRecord {
 // ============================================================
 // ГЛОБАЛЬНИЙ КАНАЛ (приватний)
 // ============================================================
-static TX: OnceLock<Sender<LogRecord>> = OnceLock::new();
+static TX: OnceLock<LogSender> = OnceLock::new();
 static MIN_LEVEL_LOG: OnceLock<i32> = OnceLock::new();
 
+/// Ємність каналу між макросами та потоком-хендлером за замовчуванням
+pub const DEFAULT_CHANNEL_CAPACITY: usize = 4096;
+
+/// Хук форматування запису логу в текстовий рядок (як `pipe_formatter` у crosvm)
+pub type Formatter = Box<dyn Fn(&LogRecord) -> String + Send>;
+
 // ============================================================
 // КОНСОЛЬНИЙ ХЕНДЛЕР (за замовчуванням)
 // ============================================================
-struct ConsoleHandler;
+pub struct ConsoleHandler {
+    formatter: Option<Formatter>,
+}
+
+impl Default for ConsoleHandler {
+    fn default() -> Self {
+        Self { formatter: None }
+    }
+}
+
+impl ConsoleHandler {
+    /// Консольний хендлер з кастомним форматуванням рядка замість кольорового за замовчуванням
+    pub fn with_formatter(formatter: Formatter) -> Self {
+        Self { formatter: Some(formatter) }
+    }
+}
 
 impl LogHandler for ConsoleHandler {
     fn handle(&mut self, record: &LogRecord) {
+        if let Some(formatter) = &self.formatter {
+            println!("{}", formatter(record));
+            return;
+        }
+
         let ts = format_log_record_time(record, "YY-MM-DD HH:MM:SS");
         let colored_heading = ansi_to_colored(record.color, record.heading).bold();
         println!("[{}] : {} -> {}", colored_heading, ts, record.msg);
@@ -42,17 +70,35 @@ impl LogHandler for ConsoleHandler {
 // ============================================================
 // ПОТІК ЛОГУВАННЯ З ХЕНДЛЕРАМИ
 // ============================================================
-fn logger_thread(rx: Receiver<LogRecord>, mut handlers: Vec<Box<dyn LogHandler>>) {
-    while let Ok(record) = rx.recv() {
-        // Передаємо запис кожному хендлеру
-        for handler in &mut handlers {
-            handler.handle(&record);
+fn logger_thread(rx: LogReceiver, mut handlers: Vec<Box<dyn LogHandler>>) {
+    let mut announced_drops = 0u64;
+
+    loop {
+        if let Some(record) = rx.recv_timeout(StdDuration::from_secs(1)) {
+            // Передаємо запис кожному хендлеру
+            for handler in &mut handlers {
+                handler.handle(&record);
+            }
+        }
+
+        // Якщо політика переповнення щось відкинула - повідомляємо про це хендлерам
+        let dropped = dropped_count();
+        if dropped > announced_drops {
+            let synthetic = LogRecord {
+                color: "\x1b[31m",
+                heading: "LOGGER",
+                target: module_path!().to_string(),
+                msg: format!("{} messages dropped", dropped - announced_drops),
+                timestamp: Utc::now(),
+                lvl: i32::MAX,
+            };
+
+            for handler in &mut handlers {
+                handler.handle(&synthetic);
+            }
+
+            announced_drops = dropped;
         }
-    }
-    
-    // Flush всіх хендлерів при завершенні
-    for handler in &mut handlers {
-        handler.flush();
     }
 }
 
@@ -109,10 +155,15 @@ pub fn internal_send_log(data: LogRecord) {
     if TX.get().is_none() {
         init_logger(0);
     }
-    
+
+    // Відсікаємо записи нижче мінімального рівня ще до потоку-хендлера
+    if !is_my_level(data.lvl) {
+        return;
+    }
+
     let tx = TX.get().expect("Logger not initialized");
-    
-    if let Err(_) = tx.send(data) {
+
+    if tx.send(data).is_err() {
         eprintln!("Logger thread died!");
     }
 }
@@ -121,59 +172,105 @@ pub fn internal_send_log(data: LogRecord) {
 // ПУБЛІЧНА ФУНКЦІЯ: Ініціалізація логера (тільки консоль)
 // ============================================================
 pub fn init_logger(min_level: i32) {
-    // Перевірка подвійної ініціалізації
-    if TX.get().is_some() {
-        panic!("Logger already initialized! Cannot initialize twice.");
-    }
-    
-    // Увімкнути ANSI підтримку в Windows
-    #[cfg(windows)]
-    {
-        let _ = enable_ansi_support::enable_ansi_support();
-    }
-    
-    let (tx, rx) = channel();
-    
-    std::thread::spawn(move || {
-        // Тільки консольний хендлер
-        let handlers: Vec<Box<dyn LogHandler>> = vec![Box::new(ConsoleHandler)];
-        logger_thread(rx, handlers);
-    });
-    
-    TX.set(tx).expect("Failed to set logger transmitter");
-    MIN_LEVEL_LOG.set(min_level).expect("Failed to set level log");
+    init_logger_with_handlers(Vec::new(), min_level);
 }
 
 // ============================================================
 // ПУБЛІЧНА ФУНКЦІЯ: Ініціалізація з кастомними хендлерами
 // ============================================================
-pub fn init_logger_with_handlers(mut custom_handlers: Vec<Box<dyn LogHandler>>, min_level: i32) {
+pub fn init_logger_with_handlers(custom_handlers: Vec<Box<dyn LogHandler>>, min_level: i32) {
+    init_logger_with_capacity(
+        custom_handlers,
+        min_level,
+        DEFAULT_CHANNEL_CAPACITY,
+        OverflowPolicy::Block,
+    );
+}
+
+// ============================================================
+// ПУБЛІЧНА ФУНКЦІЯ: Повна ініціалізація - ємність каналу і політика переповнення
+// ============================================================
+pub fn init_logger_with_capacity(
+    mut custom_handlers: Vec<Box<dyn LogHandler>>,
+    min_level: i32,
+    capacity: usize,
+    policy: OverflowPolicy,
+) {
     // Перевірка подвійної ініціалізації
     if TX.get().is_some() {
         panic!("Logger already initialized! Cannot initialize twice.");
     }
-    
+
     // Увімкнути ANSI підтримку в Windows
     #[cfg(windows)]
     {
         let _ = enable_ansi_support::enable_ansi_support();
     }
-    
-    let (tx, rx) = channel();
-    
+
+    let (tx, rx) = bounded(capacity, policy);
+
     std::thread::spawn(move || {
         // Консольний хендлер завжди перший
-        let mut handlers: Vec<Box<dyn LogHandler>> = vec![Box::new(ConsoleHandler)];
+        let mut handlers: Vec<Box<dyn LogHandler>> = vec![Box::new(ConsoleHandler::default())];
         // Додаємо кастомні хендлери
         handlers.append(&mut custom_handlers);
-        
+
         logger_thread(rx, handlers);
     });
-    
+
     TX.set(tx).expect("Failed to set logger transmitter");
     MIN_LEVEL_LOG.set(min_level).expect("Failed to set level log");
 }
 
 pub fn is_my_level(lvl: i32) -> bool {
     lvl >= *MIN_LEVEL_LOG.get().unwrap_or(&0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::{query, MemoryHandler, RecordFilter};
+    use crate::test_support::sample_record as record;
+    use std::sync::atomic::AtomicBool;
+    use std::sync::Arc;
+
+    #[test]
+    fn records_below_min_level_never_reach_a_handler() {
+        init_logger_with_handlers(vec![Box::new(MemoryHandler::new(None, None))], 2);
+
+        let mut below = record("below threshold", 1);
+        below.target = "sub_func_level_gate_test".to_string();
+        let mut above = record("above threshold", 2);
+        above.target = "sub_func_level_gate_test".to_string();
+
+        internal_send_log(below);
+        internal_send_log(above);
+
+        // даємо потоку-хендлеру час обробити записи з каналу
+        std::thread::sleep(StdDuration::from_millis(200));
+
+        let results = query(RecordFilter {
+            target: Some("sub_func_level_gate_test".to_string()),
+            ..Default::default()
+        });
+        let msgs: Vec<_> = results.iter().map(|r| r.msg.as_str()).collect();
+
+        assert!(!msgs.contains(&"below threshold"));
+        assert!(msgs.contains(&"above threshold"));
+    }
+
+    #[test]
+    fn console_handler_uses_custom_formatter_when_set() {
+        let called = Arc::new(AtomicBool::new(false));
+        let called_in_closure = called.clone();
+
+        let mut handler = ConsoleHandler::with_formatter(Box::new(move |record| {
+            called_in_closure.store(true, std::sync::atomic::Ordering::SeqCst);
+            record.msg.clone()
+        }));
+
+        handler.handle(&record("via formatter", 0));
+
+        assert!(called.load(std::sync::atomic::Ordering::SeqCst));
+    }
 }
\ No newline at end of file
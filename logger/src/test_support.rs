@@ -0,0 +1,19 @@
+// ============================================================
+// СПІЛЬНІ ДОПОМІЖНІ ФУНКЦІЇ ДЛЯ ТЕСТІВ ІНШИХ МОДУЛІВ
+// ============================================================
+#![cfg(test)]
+
+use chrono::Utc;
+
+use crate::LogRecord;
+
+pub(crate) fn sample_record(msg: &str, lvl: i32) -> LogRecord {
+    LogRecord {
+        color: "",
+        heading: "TEST",
+        target: "test".to_string(),
+        msg: msg.to_string(),
+        timestamp: Utc::now(),
+        lvl,
+    }
+}
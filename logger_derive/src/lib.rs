@@ -12,9 +12,9 @@ use syn::{parse_macro_input, DeriveInput, Lit};
 /// Використання:
 /// ```rust
 /// #[derive(LogLevel)]
-/// #[log_level(color = "\033[32m", heading = "EVENT")]
+/// #[log_level(color = "\033[32m", heading = "EVENT", level = 4)]
 /// struct Event;
-/// 
+///
 /// // Тепер можна:
 /// Event.log("Щось сталось");
 /// ```
@@ -40,12 +40,13 @@ pub fn derive_log_level(input: TokenStream) -> TokenStream {
     
     let mut color = None;
     let mut heading = None;
+    let mut level = None;
 
     // Ітеруємось по всіх атрибутах структури
     for attr in &input.attrs {
         // Шукаємо #[log_level]
         if attr.path().is_ident("log_level") {
-            // Парсимо вкладені параметри: color = "...", heading = "..."
+            // Парсимо вкладені параметри: color = "...", heading = "...", level = N
             attr.parse_nested_meta(|meta| {
                 // Перевіряємо color
                 if meta.path.is_ident("color") {
@@ -61,17 +62,25 @@ pub fn derive_log_level(input: TokenStream) -> TokenStream {
                         heading = Some(s.value());
                     }
                 }
+                // Перевіряємо level
+                else if meta.path.is_ident("level") {
+                    let value: Lit = meta.value()?.parse()?;
+                    if let Lit::Int(i) = value {
+                        level = Some(i.base10_parse::<i32>()?);
+                    }
+                }
                 Ok(())
             }).ok();
         }
     }
-    
+
     // ============================================================
     // ШАГ 3: Валідація
     // ============================================================
-    
+
     let color = color.expect("Missing #[log_level(color = \"...\")]");
     let heading = heading.expect("Missing #[log_level(heading = \"...\")]");
+    let level = level.expect("Missing #[log_level(level = N)]");
 
     // ============================================================
     // ШАГ 4: Генерація коду
@@ -89,6 +98,10 @@ pub fn derive_log_level(input: TokenStream) -> TokenStream {
             fn name(&self) -> &'static str {
                 #heading
             }
+
+            fn level(&self) -> i32 {
+                #level
+            }
         }
 
         // Додаємо метод log() до структури
@@ -98,8 +111,10 @@ pub fn derive_log_level(input: TokenStream) -> TokenStream {
                 let log = ::sample_logger::LogRecord {
                     color: #color,
                     heading: #heading,
+                    target: module_path!().to_string(),
                     msg: msg.into(),
                     timestamp: ::sample_logger::chrono::Utc::now(),
+                    lvl: #level,
                 };
                 
                 ::sample_logger::internal_send_log(log);
@@ -1,58 +1,27 @@
 // ============================================================
-// ПРИКЛАД: Кастомний файловий логер
+// ПРИКЛАД: Файловий логер з ротацією
 // ============================================================
 
-use sample_logger::{init_logger_with_handlers, LogHandler, LogRecord, LogLevel};
-use std::fs::OpenOptions;
-use std::io::Write;
-
-// Файловий хендлер
-struct FileHandler {
-    file: std::fs::File,
-}
-
-impl FileHandler {
-    fn new(path: &str) -> Self {
-        let file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(path)
-            .expect("Failed to open log file");
-        
-        Self { file }
-    }
-}
-
-impl LogHandler for FileHandler {
-    fn handle(&mut self, record: &LogRecord) {
-        // Пишемо в файл без кольорів
-        writeln!(
-            self.file,
-            "[{}] {} - {}",
-            record.heading,
-            record.timestamp.format("%Y-%m-%d %H:%M:%S"),
-            record.msg
-        ).ok();
-    }
-    
-    fn flush(&mut self) {
-        self.file.flush().ok();
-    }
-}
+use sample_logger::{
+    init_logger_with_handlers, LogLevel, RotatingFileHandler, DEFAULT_FILE_CAPACITY,
+};
 
 // Кастомні рівні
 #[derive(LogLevel)]
-#[log_level(color = "\033[32m", heading = "EVENT", level = 0)]
+#[log_level(color = "\033[32m", heading = "EVENT", level = 1)]
 struct Event;
 
 #[derive(LogLevel)]
-#[log_level(color = "\033[33m", heading = "WARN", level = 0)]
+#[log_level(color = "\033[33m", heading = "WARN", level = 1)]
 struct Warning;
 
 fn main() {
     // Ініціалізуємо з файловим логером
     // Консоль + файл одночасно!
-    init_logger_with_handlers(vec![Box::new(FileHandler::new("app.log"))], 0);
+    init_logger_with_handlers(
+        vec![Box::new(RotatingFileHandler::new("app.log", DEFAULT_FILE_CAPACITY, 5))],
+        0,
+    );
     
     println!("=== Логування в консоль + файл ===\n");
     